@@ -0,0 +1,140 @@
+// Only uses `core` functionality (no `Vec`, no `Arc`, no allocation), so
+// this module works in `no_std` contexts.
+pub use super::heap::MutHeap;
+
+/// A fixed-capacity, array-backed binary heap. Unlike `LeftistHeap` and
+/// `BinomialHeap`, this one mutates in place instead of sharing structure
+/// persistently: no pointers, so the whole structure is `Copy`/`Pod`-able
+/// when `T` is, and it can be memcpy'd or mmap'd directly into a shared
+/// memory region and reinterpreted without deserialization.
+#[derive(Clone, Copy, Debug)]
+pub struct ArrayHeap<T, const CAP: usize> {
+	size: usize,
+	nodes: [T; CAP],
+}
+
+impl<T: Ord + Copy + Default, const CAP: usize> ArrayHeap<T, CAP> {
+	/// O(1)
+	pub fn new() -> Self {
+		ArrayHeap { size: 0, nodes: [T::default(); CAP] }
+	}
+
+	pub fn capacity(&self) -> usize {
+		CAP
+	}
+
+	pub fn len(&self) -> usize {
+		self.size
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.size == 0
+	}
+}
+
+impl<T: Ord + Copy + Default, const CAP: usize> Default for ArrayHeap<T, CAP> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Ord + Copy + Default, const CAP: usize> MutHeap<T> for ArrayHeap<T, CAP> {
+	/// O(1)
+	fn is_empty(&self) -> bool {
+		ArrayHeap::is_empty(self)
+	}
+
+	/// O(log(n)). Fails with the rejected item if the heap is at capacity.
+	fn insert(&mut self, item: T) -> Result<(), T> {
+		if self.size == CAP {
+			return Err(item);
+		}
+		let mut i = self.size;
+		self.nodes[i] = item;
+		self.size += 1;
+		while i > 0 {
+			let parent = (i - 1) / 2;
+			if self.nodes[parent] <= self.nodes[i] {
+				break;
+			}
+			self.nodes.swap(parent, i);
+			i = parent;
+		}
+		Ok(())
+	}
+
+	/// O(1)
+	fn find_min(&self) -> Option<&T> {
+		if self.is_empty() {
+			None
+		} else {
+			Some(&self.nodes[0])
+		}
+	}
+
+	/// O(log(n))
+	fn delete_min(&mut self) -> Option<T> {
+		if self.is_empty() {
+			return None;
+		}
+		let min = self.nodes[0];
+		self.size -= 1;
+		self.nodes[0] = self.nodes[self.size];
+
+		let mut i = 0;
+		loop {
+			let left = 2 * i + 1;
+			let right = 2 * i + 2;
+			let mut smallest = i;
+			if left < self.size && self.nodes[left] < self.nodes[smallest] {
+				smallest = left;
+			}
+			if right < self.size && self.nodes[right] < self.nodes[smallest] {
+				smallest = right;
+			}
+			if smallest == i {
+				break;
+			}
+			self.nodes.swap(i, smallest);
+			i = smallest;
+		}
+		Some(min)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{ ArrayHeap, MutHeap };
+
+	#[test]
+	fn basics() {
+		let mut heap: ArrayHeap<i32, 4> = ArrayHeap::new();
+		assert_eq!(heap.find_min(), None);
+
+		heap.insert(2).unwrap();
+		heap.insert(1).unwrap();
+		heap.insert(3).unwrap();
+		assert_eq!(heap.find_min(), Some(&1));
+
+		assert_eq!(heap.delete_min(), Some(1));
+		assert_eq!(heap.find_min(), Some(&2));
+
+		assert_eq!(heap.delete_min(), Some(2));
+		assert_eq!(heap.delete_min(), Some(3));
+		assert_eq!(heap.delete_min(), None);
+	}
+
+	#[test]
+	fn overflow_returns_rejected_item() {
+		let mut heap: ArrayHeap<i32, 2> = ArrayHeap::new();
+		heap.insert(1).unwrap();
+		heap.insert(2).unwrap();
+		assert_eq!(heap.insert(3), Err(3));
+	}
+
+	#[test]
+	fn copyable() {
+		fn is_copy<T: Copy>(){}
+		is_copy::<ArrayHeap<i32, 8>>();
+	}
+}
@@ -1,11 +1,18 @@
 use std::sync::Arc;
 use std::cmp;
+use std::cmp::Ordering;
 use std::iter;
+use std::iter::FromIterator;
 pub use super::heap::Heap;
+pub use super::comparator::{ Comparator, OrdComparator };
 
 #[derive(Debug)]
-pub struct BinomialHeap<T> {
+pub struct BinomialHeap<T, C = OrdComparator> {
 	trees: Vec<Option<Link<T>>>,
+	comparator: Arc<C>,
+	// Derived from `trees`: the smallest element, if any. Recomputed
+	// whenever `trees` changes so `find_min` can just clone it.
+	min: Option<T>,
 }
 
 type Link<T> = Arc<Node<T>>;
@@ -16,30 +23,40 @@ struct Node<T> {
 	children: Vec<Link<T>>,
 }
 
-fn link<T: Ord + Clone>(a: &Link<T>, b: &Link<T>) -> Link<T> {
-	let (smaller, bigger) = if a.elem < b.elem { (a, b) } else { (b, a) };
+fn link<T: Clone, C: Comparator<T>>(comparator: &C, a: &Link<T>, b: &Link<T>) -> Link<T> {
+	let (smaller, bigger) = if comparator.compare(&a.elem, &b.elem) != Ordering::Greater { (a, b) } else { (b, a) };
 	let mut new_children = Vec::with_capacity(smaller.children.len() + 1);
 	new_children.extend(smaller.children.iter().cloned());
 	new_children.push(bigger.clone());
 	Arc::new(Node { elem: smaller.elem.clone(), children: new_children })
 }
 
-impl <T: Ord + Clone> Heap<T> for BinomialHeap<T> {
-	fn empty() -> Self {
-		BinomialHeap { trees: Vec::new() }
+impl<T: Clone, C: Comparator<T>> BinomialHeap<T, C> {
+	/// Like `empty()`, but takes the comparator explicitly, so it also
+	/// works for comparators that aren't `Default` and for element types
+	/// that aren't `Ord` (e.g. a `total_cmp`-based comparator over `f64`).
+	pub fn empty_with(comparator: C) -> Self {
+		BinomialHeap { trees: Vec::new(), comparator: Arc::new(comparator), min: None }
 	}
 
-	fn is_empty(&self) -> bool {
+	pub fn is_empty(&self) -> bool {
 		self.trees.is_empty()
 	}
 
-	fn insert(&self, item: T) -> Self {
-		BinomialHeap { trees: vec![
-			Some(Arc::new(Node { elem: item, children: Vec::new()}))
-		]}.merge(self)
+	pub fn insert(&self, item: T) -> Self {
+		let singleton = BinomialHeap {
+			trees: vec![Some(Arc::new(Node { elem: item.clone(), children: Vec::new()}))],
+			comparator: self.comparator.clone(),
+			min: Some(item),
+		};
+		singleton.merge(self)
 	}
 
-	fn merge<'a>(&self, other: &Self) -> Self {
+	/// `self` and `other` must share the same comparator (the result keeps
+	/// `self`'s); merging a heap ordered by a different comparator
+	/// silently produces a forest that isn't a valid binomial heap under
+	/// either ordering.
+	pub fn merge(&self, other: &Self) -> Self {
 		let cap = cmp::max(self.trees.len(), other.trees.len()) + 1;
 		let mut result: Vec<Option<Link<T>>> = Vec::with_capacity(cap);
 		let mut c: Option<Link<T>> = None;
@@ -54,40 +71,93 @@ impl <T: Ord + Clone> Heap<T> for BinomialHeap<T> {
 				(Some(x), None, None) => Some(x.clone()),
 				(None, Some(y), None) => Some(y.clone()),
 				(None, None, Some(z)) => Some(z.clone()),
-				(Some(x), Some(y), None) => { c = Some(link(x,y)); None }
-				(Some(x), None, Some(z)) => { c = Some(link(x,&z)); None }
-				(None, Some(y), Some(z)) => { c = Some(link(y,&z)); None }
-				(Some(x), Some(y), Some(z)) => { c = Some(link(x,y)); Some(z) }
+				(Some(x), Some(y), None) => { c = Some(link(&*self.comparator, x, y)); None }
+				(Some(x), None, Some(z)) => { c = Some(link(&*self.comparator, x, &z)); None }
+				(None, Some(y), Some(z)) => { c = Some(link(&*self.comparator, y, &z)); None }
+				(Some(x), Some(y), Some(z)) => { c = Some(link(&*self.comparator, x, y)); Some(z) }
 			});
 		}
 		if let Some(&None) = result.last() {
 			result.pop();
 		}
-		BinomialHeap { trees: result }
+		BinomialHeap { trees: result, comparator: self.comparator.clone(), min: smaller(&*self.comparator, &self.min, &other.min) }
 	}
 
-	fn find_min(&self) -> Option<T> {
-		match min_index(&self.trees) {
-			None => None,
-			Some(index) => Some(self.trees[index].as_ref().unwrap().elem.clone())
-		}
+	/// O(1): the minimum is cached on `trees`' last mutation instead of
+	/// scanned for here.
+	pub fn find_min(&self) -> Option<T> {
+		self.min.clone()
 	}
 
-	fn delete_min(&self) -> Self {
-		match min_index(&self.trees) {
-			None => Heap::empty(),
+	pub fn delete_min(&self) -> Self {
+		match min_index(&*self.comparator, &self.trees) {
+			None => BinomialHeap { trees: Vec::new(), comparator: self.comparator.clone(), min: None },
 			Some(index) => {
 				let mut old_trees = self.trees.clone();
 				let taken = old_trees[index].take().unwrap();
-				let old = BinomialHeap { trees: old_trees };
-				old.merge(&BinomialHeap { trees: taken.children.iter().cloned().map(Some).collect() })
+				let old_min = cached_min(&*self.comparator, &old_trees);
+				let old = BinomialHeap { trees: old_trees, comparator: self.comparator.clone(), min: old_min };
+				let orphan_trees: Vec<Option<Link<T>>> = taken.children.iter().cloned().map(Some).collect();
+				let orphans_min = cached_min(&*self.comparator, &orphan_trees);
+				let orphans = BinomialHeap { trees: orphan_trees, comparator: self.comparator.clone(), min: orphans_min };
+				old.merge(&orphans)
 			}
 		}
 	}
 }
 
+impl<T: Ord + Clone, C: Comparator<T> + Default> BinomialHeap<T, C> {
+	/// One-call heapsort: drain the heap into a sorted `Vec`. O(n log(n))
+	pub fn into_sorted_vec(self) -> Vec<T> {
+		self.into_iter().collect()
+	}
+}
+
+impl <T: Ord + Clone, C: Comparator<T> + Default> Heap<T> for BinomialHeap<T, C> {
+	fn empty() -> Self {
+		Self::empty_with(C::default())
+	}
+
+	fn is_empty(&self) -> bool {
+		BinomialHeap::is_empty(self)
+	}
+
+	fn insert(&self, item: T) -> Self {
+		BinomialHeap::insert(self, item)
+	}
+
+	fn merge(&self, other: &Self) -> Self {
+		BinomialHeap::merge(self, other)
+	}
+
+	fn find_min(&self) -> Option<T> {
+		BinomialHeap::find_min(self)
+	}
+
+	fn delete_min(&self) -> Self {
+		BinomialHeap::delete_min(self)
+	}
+}
+
+// Combine two already-cached minima into the minimum of the union, without
+// rescanning either side's trees.
+fn smaller<T: Clone, C: Comparator<T>>(comparator: &C, a: &Option<T>, b: &Option<T>) -> Option<T> {
+	match (a, b) {
+		(None, None) => None,
+		(Some(a), None) => Some(a.clone()),
+		(None, Some(b)) => Some(b.clone()),
+		(Some(a), Some(b)) => Some(if comparator.compare(a, b) != Ordering::Greater { a.clone() } else { b.clone() }),
+	}
+}
+
+// Scan the roots for the minimum, for use when rebuilding the cache after a
+// tree is removed out from under it (e.g. the taken root in `delete_min`).
+fn cached_min<T: Clone, C: Comparator<T>>(comparator: &C, trees: &Vec<Option<Link<T>>>) -> Option<T> {
+	min_index(comparator, trees).map(|index| trees[index].as_ref().unwrap().elem.clone())
+}
+
 // Utility function to find the smallest index in the heap
-fn min_index<T: Ord>(vec: &Vec<Option<Link<T>>>) -> Option<usize> {
+fn min_index<T, C: Comparator<T>>(comparator: &C, vec: &Vec<Option<Link<T>>>) -> Option<usize> {
 	let mut i = vec.iter()                                           // Iterator<&Option<Link<T>>>
 	               .enumerate()                                      // Iterator<(usize, &Option<Link<T>>)
 	               .map(|(i, x)| x.as_ref().map(|v| (i, &(v.elem)))) // Iterator<Option<(usize, &T)>>
@@ -101,12 +171,48 @@ fn min_index<T: Ord>(vec: &Vec<Option<Link<T>>>) -> Option<usize> {
 	};
 
 	// Compare all the values, find the smallest, and return its index
-	Some(i.fold((ix, init), |(ai, a), (bi, b)| if &a < &b { (ai, a) } else { (bi, b) }).0)
+	Some(i.fold((ix, init), |(ai, a), (bi, b)| if comparator.compare(a, b) != Ordering::Greater { (ai, a) } else { (bi, b) }).0)
+}
+
+pub struct IntoIter<T, C = OrdComparator> {
+	next: BinomialHeap<T, C>,
+}
+
+impl<T: Ord + Clone, C: Comparator<T> + Default> Iterator for IntoIter<T, C> {
+	type Item = T;
+	fn next(&mut self) -> Option<Self::Item> {
+		let item = self.next.find_min();
+		self.next = self.next.delete_min();
+		item
+	}
+}
+
+impl<T: Ord + Clone, C: Comparator<T> + Default> IntoIterator for BinomialHeap<T, C> {
+	type Item = T;
+	type IntoIter = IntoIter<T, C>;
+	fn into_iter(self) -> IntoIter<T, C> {
+		IntoIter { next: self }
+	}
+}
+
+impl<T: Ord + Clone, C: Comparator<T> + Default> FromIterator<T> for BinomialHeap<T, C> {
+	/// Each insert is a full carry-propagating merge that walks every rank
+	/// up to the tree count, so folding n of them is O(n log(n)), not the
+	/// O(n) a binary counter's amortized analysis might suggest -- `merge`
+	/// never breaks out of that walk early once the carry chain ends.
+	fn from_iter<I>(iterator: I) -> Self where I: IntoIterator<Item=T> {
+		let mut heap: BinomialHeap<T, C> = Heap::empty();
+		for item in iterator {
+			heap = heap.insert(item);
+		}
+		heap
+	}
 }
 
 #[cfg(test)]
 mod test {
 	use super::{ BinomialHeap, Heap };
+	use std::iter::FromIterator;
 
 	#[test]
 	fn basics() {
@@ -139,4 +245,54 @@ mod test {
 
 		assert!(true);
 	}
+
+	#[test]
+	fn from_iter_and_into_sorted_vec() {
+		let original = vec![5,1,7,3,2,6,4];
+		let heap = BinomialHeap::<i32>::from_iter(original);
+
+		let ordered = heap.into_sorted_vec();
+		let sequence = Vec::<i32>::from_iter(1..8);
+		assert_eq!(ordered, sequence);
+	}
+
+	#[test]
+	fn from_iter_empty() {
+		let original = vec![];
+		let heap = BinomialHeap::<i32>::from_iter(original);
+
+		let ordered = heap.into_sorted_vec();
+		assert_eq!(ordered.len(), 0);
+	}
+
+	use super::super::comparator::Comparator;
+	use std::cmp::Ordering;
+
+	struct MaxComparator;
+	impl Comparator<i32> for MaxComparator {
+		fn compare(&self, a: &i32, b: &i32) -> Ordering {
+			b.cmp(a)
+		}
+	}
+
+	#[test]
+	fn cached_min_survives_merge() {
+		let a: BinomialHeap<i32> = Heap::empty();
+		let a = a.insert(9).insert(4);
+		let b: BinomialHeap<i32> = Heap::empty();
+		let b = b.insert(7).insert(1);
+
+		let merged = a.merge(&b);
+		assert_eq!(merged.find_min(), Some(1));
+	}
+
+	#[test]
+	fn custom_comparator() {
+		let heap = BinomialHeap::<i32, MaxComparator>::empty_with(MaxComparator);
+		let heap = heap.insert(2).insert(1).insert(3);
+		assert_eq!(heap.find_min(), Some(3));
+
+		let heap = heap.delete_min();
+		assert_eq!(heap.find_min(), Some(2));
+	}
 }
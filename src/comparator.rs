@@ -0,0 +1,18 @@
+use std::cmp::Ordering;
+
+/// Orders elements for a heap. Parameterizing a heap over a `Comparator`
+/// lets callers build max-heaps or heaps keyed on a derived field without
+/// wrapping every element in a `Reverse`-style newtype.
+pub trait Comparator<T> {
+	fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+/// The default comparator: defers to `T`'s own `Ord` implementation.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OrdComparator;
+
+impl<T: Ord> Comparator<T> for OrdComparator {
+	fn compare(&self, a: &T, b: &T) -> Ordering {
+		a.cmp(b)
+	}
+}
@@ -7,6 +7,15 @@ pub trait Heap<T: Ord + Clone> {
 	fn delete_min(&self) -> Self;
 }
 
+/// Sibling of `Heap` for heaps that mutate in place rather than sharing
+/// structure persistently (e.g. a fixed-capacity array backing).
+pub trait MutHeap<T: Ord> {
+	fn is_empty(&self) -> bool;
+	fn insert(&mut self, item: T) -> Result<(), T>;
+	fn find_min(&self) -> Option<&T>;
+	fn delete_min(&mut self) -> Option<T>;
+}
+
 #[cfg(test)]
 pub mod properties {
 	extern crate quickcheck;
@@ -0,0 +1,115 @@
+use std::cmp::Ordering;
+use std::iter::FromIterator;
+pub use super::heap::Heap;
+use super::leftist_heap::LeftistHeap;
+
+/// Pairs an iterator's next element (`head`) with what's left of the
+/// iterator (`tail`), ordered purely by `head` so the crate's min-heap
+/// always surfaces the input with the smallest next element.
+struct HeadTail<I: Iterator> {
+	head: I::Item,
+	tail: I,
+}
+
+impl<I: Iterator> HeadTail<I> {
+	fn new(mut iter: I) -> Option<Self> {
+		iter.next().map(|head| HeadTail { head: head, tail: iter })
+	}
+}
+
+impl<I: Iterator + Clone> Clone for HeadTail<I> where I::Item: Clone {
+	fn clone(&self) -> Self {
+		HeadTail { head: self.head.clone(), tail: self.tail.clone() }
+	}
+}
+
+impl<I: Iterator> PartialEq for HeadTail<I> where I::Item: PartialEq {
+	fn eq(&self, other: &Self) -> bool {
+		self.head == other.head
+	}
+}
+
+impl<I: Iterator> Eq for HeadTail<I> where I::Item: Eq {}
+
+impl<I: Iterator> PartialOrd for HeadTail<I> where I::Item: Ord {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<I: Iterator> Ord for HeadTail<I> where I::Item: Ord {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.head.cmp(&other.head)
+	}
+}
+
+/// Lazily merges already-sorted iterators into a single sorted stream,
+/// using a `LeftistHeap` as the selector. O(total * log k) for `k` inputs.
+pub struct Kmerge<I: Iterator> {
+	heap: LeftistHeap<HeadTail<I>>,
+}
+
+impl<I: Iterator + Clone> Iterator for Kmerge<I> where I::Item: Ord + Clone {
+	type Item = I::Item;
+
+	/// O(log(k))
+	fn next(&mut self) -> Option<I::Item> {
+		let HeadTail { head, mut tail } = self.heap.find_min()?;
+		self.heap = self.heap.delete_min();
+		if let Some(next_head) = tail.next() {
+			self.heap = self.heap.insert(HeadTail { head: next_head, tail: tail });
+		}
+		Some(head)
+	}
+}
+
+/// Lazily merge an iterator of already-sorted iterators into one sorted
+/// stream. Empty inputs are dropped; a single-element input is inserted
+/// then immediately exhausted on the next call.
+pub fn kmerge<J, I>(iters: J) -> Kmerge<I>
+	where J: IntoIterator<Item=I>, I: Iterator + Clone, I::Item: Ord + Clone
+{
+	let heap = LeftistHeap::from_iter(iters.into_iter().filter_map(HeadTail::new));
+	Kmerge { heap: heap }
+}
+
+#[cfg(test)]
+mod test {
+	use super::kmerge;
+
+	#[test]
+	fn merges_sorted_iterators() {
+		let inputs = vec![
+			vec![1, 4, 7].into_iter(),
+			vec![2, 5, 8].into_iter(),
+			vec![3, 6, 9].into_iter(),
+		];
+		let merged: Vec<i32> = kmerge(inputs).collect();
+		assert_eq!(merged, (1..10).collect::<Vec<i32>>());
+	}
+
+	#[test]
+	fn drops_empty_inputs() {
+		let inputs: Vec<std::vec::IntoIter<i32>> = vec![
+			vec![].into_iter(),
+			vec![1, 2].into_iter(),
+			vec![].into_iter(),
+		];
+		let merged: Vec<i32> = kmerge(inputs).collect();
+		assert_eq!(merged, vec![1, 2]);
+	}
+
+	#[test]
+	fn empty_outer_iterator_yields_nothing() {
+		let inputs: Vec<std::vec::IntoIter<i32>> = vec![];
+		let merged: Vec<i32> = kmerge(inputs).collect();
+		assert_eq!(merged, Vec::<i32>::new());
+	}
+
+	#[test]
+	fn single_element_inputs() {
+		let inputs = vec![vec![2].into_iter(), vec![1].into_iter()];
+		let merged: Vec<i32> = kmerge(inputs).collect();
+		assert_eq!(merged, vec![1, 2]);
+	}
+}
@@ -1,10 +1,13 @@
 use std::sync::Arc;
+use std::cmp::Ordering;
 use std::iter::FromIterator;
 pub use super::heap::Heap;
+pub use super::comparator::{ Comparator, OrdComparator };
 
 #[derive(Debug)]
-pub struct LeftistHeap<T> {
+pub struct LeftistHeap<T, C = OrdComparator> {
 	head: Link<T>,
+	comparator: Arc<C>,
 }
 
 type Link<T> = Option<Arc<Node<T>>>;
@@ -33,35 +36,41 @@ fn rank<T>(link: &Link<T>) -> i32 {
 	}
 }
 
-impl<T: Ord + Clone> Heap<T> for LeftistHeap<T> {
-	/// O(1)
-	fn empty() -> Self {
-		LeftistHeap { head: None }
+impl<T: Clone, C: Comparator<T>> LeftistHeap<T, C> {
+	/// O(1). Like `empty()`, but takes the comparator explicitly, so it
+	/// also works for comparators that aren't `Default` and for element
+	/// types that aren't `Ord` (e.g. a `total_cmp`-based comparator over
+	/// `f64`).
+	pub fn empty_with(comparator: C) -> Self {
+		LeftistHeap { head: None, comparator: Arc::new(comparator) }
 	}
 
 	/// O(1)
-	fn is_empty(&self) -> bool {
+	pub fn is_empty(&self) -> bool {
 		self.head.is_none()
 	}
 
 	/// O(log(n))
-	fn insert(&self, item: T) -> Self {
-		self.merge(&LeftistHeap { head: link(1, item, None, None) })
+	pub fn insert(&self, item: T) -> Self {
+		let singleton = LeftistHeap { head: link(1, item, None, None), comparator: self.comparator.clone() };
+		self.merge(&singleton)
 	}
 
-
-	/// O(log(n))
-	fn merge(&self, other: &Self) -> Self {
+	/// O(log(n)). `self` and `other` must share the same comparator (the
+	/// result keeps `self`'s); merging a heap ordered by a different
+	/// comparator silently produces a tree that isn't a valid leftist
+	/// heap under either ordering.
+	pub fn merge(&self, other: &Self) -> Self {
 		LeftistHeap { head: match (self.head.as_ref(), other.head.as_ref()) {
 			(None, None) => None,
 			(Some(h1), None) => Some(h1.clone()),
 			(None, Some(h2)) => Some(h2.clone()),
 			(Some(h1), Some(h2)) => {
-				let (elem, a, b) = if h1.elem <= h2.elem {
-					let wrapped = LeftistHeap { head: h1.b.clone() };
-					(h1.elem.clone(), h1.a.clone(), (&wrapped).merge(other).head)
+				let (elem, a, b) = if self.comparator.compare(&h1.elem, &h2.elem) != Ordering::Greater {
+					let wrapped = LeftistHeap { head: h1.b.clone(), comparator: self.comparator.clone() };
+					(h1.elem.clone(), h1.a.clone(), wrapped.merge(other).head)
 				} else {
-					let wrapped = LeftistHeap { head: h2.b.clone() };
+					let wrapped = LeftistHeap { head: h2.b.clone(), comparator: self.comparator.clone() };
 					(h2.elem.clone(), h2.a.clone(), self.merge(&wrapped).head)
 				};
 				let ra = rank(&a);
@@ -72,34 +81,68 @@ impl<T: Ord + Clone> Heap<T> for LeftistHeap<T> {
 					link(ra + 1, elem, b, a)
 				}
 			}
-		}}
+		}, comparator: self.comparator.clone() }
 	}
 
 	/// O(1)
-	fn find_min(&self) -> Option<T> {
+	pub fn find_min(&self) -> Option<T> {
 		self.head.as_ref().map(|node| {
 			node.elem.clone()
 		})
 	}
 
 	/// O(log(n))
-	fn delete_min(&self) -> Self {
+	pub fn delete_min(&self) -> Self {
 		match self.head.as_ref() {
-			None => Self::empty(),
+			None => LeftistHeap { head: None, comparator: self.comparator.clone() },
 			Some(node) => {
-				let wrapped_a = LeftistHeap { head: node.a.clone() };
-				let wrapped_b = LeftistHeap { head: node.b.clone() };
+				let wrapped_a = LeftistHeap { head: node.a.clone(), comparator: self.comparator.clone() };
+				let wrapped_b = LeftistHeap { head: node.b.clone(), comparator: self.comparator.clone() };
 				wrapped_a.merge(&wrapped_b)
 			}
 		}
 	}
 }
 
-pub struct IntoIter<T> {
-	next: LeftistHeap<T>,
+impl<T: Ord + Clone, C: Comparator<T> + Default> LeftistHeap<T, C> {
+	/// One-call heapsort: drain the heap into a sorted `Vec`. O(n log(n))
+	pub fn into_sorted_vec(self) -> Vec<T> {
+		self.into_iter().collect()
+	}
 }
 
-impl<T: Ord + Clone> Iterator for IntoIter<T> {
+impl<T: Ord + Clone, C: Comparator<T> + Default> Heap<T> for LeftistHeap<T, C> {
+	/// O(1)
+	fn empty() -> Self {
+		Self::empty_with(C::default())
+	}
+
+	fn is_empty(&self) -> bool {
+		LeftistHeap::is_empty(self)
+	}
+
+	fn insert(&self, item: T) -> Self {
+		LeftistHeap::insert(self, item)
+	}
+
+	fn merge(&self, other: &Self) -> Self {
+		LeftistHeap::merge(self, other)
+	}
+
+	fn find_min(&self) -> Option<T> {
+		LeftistHeap::find_min(self)
+	}
+
+	fn delete_min(&self) -> Self {
+		LeftistHeap::delete_min(self)
+	}
+}
+
+pub struct IntoIter<T, C = OrdComparator> {
+	next: LeftistHeap<T, C>,
+}
+
+impl<T: Ord + Clone, C: Comparator<T> + Default> Iterator for IntoIter<T, C> {
 	type Item = T;
 	fn next(&mut self) -> Option<Self::Item> {
 		let item = self.next.find_min();
@@ -108,21 +151,22 @@ impl<T: Ord + Clone> Iterator for IntoIter<T> {
 	}
 }
 
-impl<T: Ord + Clone> IntoIterator for LeftistHeap<T> {
+impl<T: Ord + Clone, C: Comparator<T> + Default> IntoIterator for LeftistHeap<T, C> {
 	type Item = T;
-	type IntoIter = IntoIter<T>;
-	fn into_iter(self) -> IntoIter<T> {
+	type IntoIter = IntoIter<T, C>;
+	fn into_iter(self) -> IntoIter<T, C> {
 		IntoIter { next: self }
 	}
 }
 
-impl<T: Ord + Clone> FromIterator<T> for LeftistHeap<T> {
+impl<T: Ord + Clone, C: Comparator<T> + Default> FromIterator<T> for LeftistHeap<T, C> {
 	/// full iteration = O(n)
 	fn from_iter<I>(iterator: I) -> Self where I: IntoIterator<Item=T> {
+		let comparator = Arc::new(C::default());
 		let mut iter = iterator.into_iter();
-		let mut stack: Vec<LeftistHeap<T>> = vec![];
+		let mut stack: Vec<LeftistHeap<T, C>> = vec![];
 		while let Some(item) = iter.next() {
-			stack.push(LeftistHeap { head: link(1, item, None, None) });
+			stack.push(LeftistHeap { head: link(1, item, None, None), comparator: comparator.clone() });
 			loop {
 				// Only merge similar sized heaps
 				let end = stack.len();
@@ -203,6 +247,13 @@ mod test {
 		assert_eq!(ordered.len(), 0);
 	}
 
+	#[test]
+	fn into_sorted_vec() {
+		let original = vec![5,1,7,3,2,6,4];
+		let heap = LeftistHeap::<i32>::from_iter(original);
+		assert_eq!(heap.into_sorted_vec(), Vec::<i32>::from_iter(1..8));
+	}
+
 	#[test]
 	fn thread_safety() {
 		fn is_send<T: Send>(){}
@@ -220,4 +271,24 @@ mod test {
 	fn heap_quickchecks() {
 		properties::sorting::<i32, LeftistHeap<i32>>();
 	}
+
+	use super::super::comparator::Comparator;
+	use std::cmp::Ordering;
+
+	struct MaxComparator;
+	impl Comparator<i32> for MaxComparator {
+		fn compare(&self, a: &i32, b: &i32) -> Ordering {
+			b.cmp(a)
+		}
+	}
+
+	#[test]
+	fn custom_comparator() {
+		let heap = LeftistHeap::<i32, MaxComparator>::empty_with(MaxComparator);
+		let heap = heap.insert(2).insert(1).insert(3);
+		assert_eq!(heap.find_min(), Some(3));
+
+		let heap = heap.delete_min();
+		assert_eq!(heap.find_min(), Some(2));
+	}
 }